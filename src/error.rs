@@ -0,0 +1,61 @@
+// SPDX-FileCopyrightText: 2020 Robin Krahl <robin.krahl@ireas.org>
+// SPDX-License-Identifier: Apache-2.0 or MIT
+
+//! Errors returned by this crate.
+
+use std::error;
+use std::fmt;
+
+/// An error that occurred while generating a PDF document.
+#[derive(Debug)]
+pub struct Error {
+    message: String,
+    kind: ErrorKind,
+}
+
+impl Error {
+    /// Creates a new error with the given message and kind or source error.
+    pub fn new(message: impl Into<String>, kind: impl Into<ErrorKind>) -> Error {
+        Error {
+            message: message.into(),
+            kind: kind.into(),
+        }
+    }
+
+    /// Returns the kind of this error.
+    pub fn kind(&self) -> &ErrorKind {
+        &self.kind
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match &self.kind {
+            ErrorKind::InvalidFont => None,
+            ErrorKind::Other(err) => Some(err.as_ref()),
+        }
+    }
+}
+
+/// The kind of an [`Error`][].
+///
+/// [`Error`]: struct.Error.html
+#[derive(Debug)]
+pub enum ErrorKind {
+    /// The font data is invalid, unreadable or missing a required face.
+    InvalidFont,
+    /// Any other error, usually caused by an underlying library.
+    Other(Box<dyn error::Error + Send + Sync>),
+}
+
+impl<E: error::Error + Send + Sync + 'static> From<E> for ErrorKind {
+    fn from(err: E) -> ErrorKind {
+        ErrorKind::Other(Box::new(err))
+    }
+}
@@ -10,12 +10,27 @@
 //!
 //! The [`FontCache`][] caches all loaded fonts.  A [`Font`][] is a reference to a cached font in
 //! the [`FontCache`][].  A [`FontFamily`][] is a collection of a regular, a bold, an italic and a
-//! bold italic font (raw data or cached).
+//! bold italic font (raw data or cached).  Besides [`from_files`][], you can use [`from_system`][]
+//! to look up a font family by name among the fonts installed on the system.
 //!
 //! Add fonts to a document’s font cache by calling [`Document::add_font_family`][].  This method
 //! returns a reference to the cached data that you then can use with the [`Style`][] struct to
 //! change the font family of an element.
 //!
+//! If a font does not cover every character you draw with it, register one or more fallback font
+//! families with [`FontCache::add_fallback_family`][]; characters missing from the selected font
+//! are measured and drawn with the first fallback family that does cover them.
+//!
+//! Text is normally laid out horizontally; attach a [`FontTransform`][] to a [`Style`][] with
+//! [`Style::with_transform`][] to rotate a run of text by 90, 180 or 270 degrees instead, for
+//! example for table column headers or side annotations.  Use [`Font::bounds`][] instead of
+//! [`Font::str_width`][]/[`Font::get_line_height`][] directly when computing layout space for
+//! rotated text, since it swaps the advance and line axes for you.
+//!
+//! If you don't need to embed a font at all, [`FontData::builtin`][] loads one of the 14 standard
+//! PDF fonts ([`BuiltinFont`][]) that every compliant viewer already ships, at the cost of only
+//! reliably covering a WinAnsi-ish encoding.
+//!
 //! **Note:**  The [`Font`][] and [`FontFamily<Font>`][`FontFamily`] structs are only valid for the
 //! [`FontCache`][] they have been created with.  If you dont use the low-level [`render`][] module
 //! directly, only use the [`Document::add_font_family`][] method to add fonts!
@@ -27,6 +42,8 @@
 //! [`FontData::load`][] and [`FontData::new`][] methods.  Once the PDF document is rendered, a
 //! [`printpdf::IndirectFontRef`][] is used to draw text in the PDF document.  Before a font can be
 //! used in a PDF document, it has to be embedded using the [`FontCache::load_pdf_fonts`][] method.
+//! Call [`FontCache::enable_subsetting`][] beforehand to have that method embed only the glyphs a
+//! document actually uses instead of the complete font program.
 //!
 //! If you use the high-level interface provided by [`Document`][] to generate a PDF document, these
 //! steps are done automatically.  You only have to manually populate the font cache if you use the
@@ -36,18 +53,30 @@
 //! [`Document`]: ../struct.Document.html
 //! [`Document::add_font_family`]: ../struct.Document.html#method.add_font_family
 //! [`Style`]: ../style/struct.Style.html
+//! [`Style::with_transform`]: ../style/struct.Style.html#method.with_transform
 //! [`from_files`]: fn.from_files.html
+//! [`from_system`]: fn.from_system.html
 //! [`FontCache`]: struct.FontCache.html
+//! [`FontCache::add_fallback_family`]: struct.FontCache.html#method.add_fallback_family
+//! [`FontCache::enable_subsetting`]: struct.FontCache.html#method.enable_subsetting
 //! [`FontCache::load_pdf_fonts`]: struct.FontCache.html#method.load_pdf_fonts
 //! [`FontData`]: struct.FontData.html
 //! [`FontData::new`]: struct.FontData.html#method.new
 //! [`FontData::load`]: struct.FontData.html#method.load
+//! [`FontData::builtin`]: struct.FontData.html#method.builtin
+//! [`BuiltinFont`]: enum.BuiltinFont.html
 //! [`Font`]: struct.Font.html
+//! [`Font::bounds`]: struct.Font.html#method.bounds
+//! [`Font::get_line_height`]: struct.Font.html#method.get_line_height
+//! [`Font::str_width`]: struct.Font.html#method.str_width
 //! [`FontFamily`]: struct.FontFamily.html
+//! [`FontTransform`]: enum.FontTransform.html
 //! [`rusttype`]: https://docs.rs/rusttype
 //! [`rusttype::Font`]: https://docs.rs/rusttype/0.8.3/rusttype/struct.Font.html
 //! [`printpdf::IndirectFontRef`]: https://docs.rs/printpdf/0.3.2/printpdf/types/plugins/graphics/two_dimensional/font/struct.IndirectFontRef.html
 
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
 use std::fmt;
 use std::fs;
 use std::path;
@@ -73,6 +102,18 @@ pub struct FontCache {
     // a font, but the default font is always loaded in new, so this options is always some
     // (outside of new).
     default_font_family: Option<FontFamily<Font>>,
+    // Font families consulted in order when the default/selected font does not cover a
+    // character, see add_fallback_family.
+    fallback_families: Vec<FontFamily<Font>>,
+    // Caches the unscaled advance width of a (font, character) pair so that char_width doesn't
+    // have to rebuild a standalone glyph for characters it has already measured.
+    metrics_cache: RefCell<HashMap<(usize, char), f32>>,
+    // The characters that have actually been measured (and therefore drawn) with each font,
+    // keyed by font index, so that load_pdf_fonts can subset embedded fonts down to the glyphs a
+    // document actually uses; see enable_subsetting.
+    used_chars: RefCell<HashMap<usize, HashSet<char>>>,
+    // Whether load_pdf_fonts should subset embedded fonts to used_chars; see enable_subsetting.
+    subsetting_enabled: bool,
 }
 
 impl FontCache {
@@ -82,14 +123,36 @@ impl FontCache {
             fonts: Vec::new(),
             pdf_fonts: Vec::new(),
             default_font_family: None,
+            fallback_families: Vec::new(),
+            metrics_cache: RefCell::new(HashMap::new()),
+            used_chars: RefCell::new(HashMap::new()),
+            subsetting_enabled: false,
         };
         font_cache.default_font_family = Some(font_cache.add_font_family(default_font_family)?);
         Ok(font_cache)
     }
 
+    /// Enables or disables glyph subsetting for embedded fonts.
+    ///
+    /// When enabled, [`load_pdf_fonts`][] embeds only the glyphs for the characters that have
+    /// been measured with each font by [`Font::char_width`][] up to that call, instead of the
+    /// whole font program, which can shrink a document using only a handful of characters from a
+    /// large Unicode font by orders of magnitude.  Characters that are first measured after
+    /// [`load_pdf_fonts`][] has been called are not in the subset.  Leave this disabled if the
+    /// embedded fonts need to remain complete, for example because the PDF may be edited or have
+    /// text extracted from it later.
+    ///
+    /// Subsetting is disabled by default.
+    ///
+    /// [`load_pdf_fonts`]: #method.load_pdf_fonts
+    /// [`Font::char_width`]: struct.Font.html#method.char_width
+    pub fn enable_subsetting(&mut self, enabled: bool) {
+        self.subsetting_enabled = enabled;
+    }
+
     /// Adds the given font to the cache and returns a reference to it.
     pub fn add_font(&mut self, font_data: FontData) -> Result<Font, Error> {
-        let font = Font::new(self.fonts.len(), &font_data.rt_font)?;
+        let font = Font::new(self.fonts.len(), &font_data.metrics)?;
         self.fonts.push(font_data);
         Ok(font)
     }
@@ -107,13 +170,151 @@ impl FontCache {
         })
     }
 
+    /// Adds the given font family to the cache as a fallback family and returns a reference to
+    /// it.
+    ///
+    /// Fallback families are consulted, in registration order, whenever a character is not
+    /// covered by the font that would otherwise be used to render it (for example the family
+    /// passed to [`new`][] or selected through [`Style`][]).  This lets you register, say, a CJK
+    /// or an emoji font alongside a Latin default font so that characters the default font lacks
+    /// still render instead of falling back to `.notdef` boxes.
+    ///
+    /// [`new`]: #method.new
+    /// [`Style`]: ../style/struct.Style.html
+    pub fn add_fallback_family(
+        &mut self,
+        family: FontFamily<FontData>,
+    ) -> Result<FontFamily<Font>, Error> {
+        let family = self.add_font_family(family)?;
+        self.fallback_families.push(family);
+        Ok(family)
+    }
+
+    /// Returns the font that should be used to render `c`, starting with `font` and falling back
+    /// to the families registered with [`add_fallback_family`][] (matched by `style`) if `font`'s
+    /// underlying face has no glyph for `c`.
+    ///
+    /// [`add_fallback_family`]: #method.add_fallback_family
+    fn resolve_font(&self, font: Font, style: Style, c: char) -> Font {
+        if self.has_glyph(font, c) {
+            return font;
+        }
+        for fallback in &self.fallback_families {
+            let candidate = fallback.get(style);
+            if self.has_glyph(candidate, c) {
+                return candidate;
+            }
+        }
+        font
+    }
+
+    /// Returns `true` if `font` has a real glyph (and not just the missing-glyph id `0`, commonly
+    /// known as `.notdef`) for `c`.
+    ///
+    /// A [`BuiltinFont`][]'s bundled metrics only cover the printable WinAnsi/ASCII range, so
+    /// outside of that range it is always reported as not covering `c`.
+    ///
+    /// [`BuiltinFont`]: enum.BuiltinFont.html
+    fn has_glyph(&self, font: Font, c: char) -> bool {
+        match &self.fonts[font.idx].metrics {
+            FontMetrics::RustType(rt_font) => rt_font.glyph(c).id().0 != 0,
+            FontMetrics::Builtin(builtin) => builtin.covers(c),
+        }
+    }
+
+    /// Returns the unscaled advance width of `c` in `font` (i.e. the advance width divided by the
+    /// font's `units_per_em`), computing and caching it on the first call for this `(font, c)`
+    /// pair.
+    ///
+    /// Building a standalone glyph to read its metrics is comparatively expensive, and the same
+    /// characters tend to get measured over and over while laying out a document, so this is
+    /// cached instead of recomputed on every call to [`Font::char_width`][].
+    ///
+    /// [`Font::char_width`]: struct.Font.html#method.char_width
+    fn unscaled_advance(&self, font: Font, c: char) -> f32 {
+        let key = (font.idx, c);
+        if let Some(advance) = self.metrics_cache.borrow().get(&key) {
+            return *advance;
+        }
+        let advance = match &self.fonts[font.idx].metrics {
+            FontMetrics::RustType(rt_font) => {
+                let glyph = rt_font
+                    .glyph(c)
+                    .standalone()
+                    .get_data()
+                    .expect("No data for standalone glyph");
+                glyph.unit_h_metrics.advance_width / font.scale
+            }
+            FontMetrics::Builtin(builtin) => builtin.advance_width(c),
+        };
+        self.metrics_cache.borrow_mut().insert(key, advance);
+        advance
+    }
+
+    /// Returns the kerning adjustment to apply between `first` and `second` when they are drawn
+    /// consecutively with `font` at `font_size`.
+    ///
+    /// [`BuiltinFont`][]s don't carry kerning tables, so this is always zero for them.
+    ///
+    /// [`BuiltinFont`]: enum.BuiltinFont.html
+    fn kerning(&self, font: Font, first: char, second: char, font_size: u8) -> Mm {
+        match &self.fonts[font.idx].metrics {
+            FontMetrics::RustType(rt_font) => {
+                // pair_kerning scales so that ascent - descent spans the given size, while advance
+                // widths are scaled by units_per_em, so read the kerning in font units first.
+                let v_metrics = rt_font.v_metrics_unscaled();
+                let units = rusttype::Scale::uniform(v_metrics.ascent - v_metrics.descent);
+                let adjustment =
+                    rt_font.pair_kerning(units, first, second) / font.scale * f32::from(font_size);
+                Mm::from(printpdf::Pt(f64::from(adjustment)))
+            }
+            FontMetrics::Builtin(_) => Mm::from(printpdf::Pt(0.0)),
+        }
+    }
+
+    /// Records that `c` has been measured (and is therefore about to be drawn) with `font`, so
+    /// that [`load_pdf_fonts`][] can subset the embedded font down to the glyphs actually used if
+    /// [`enable_subsetting`][] was called.
+    ///
+    /// [`load_pdf_fonts`]: #method.load_pdf_fonts
+    /// [`enable_subsetting`]: #method.enable_subsetting
+    fn record_usage(&self, font: Font, c: char) {
+        self.used_chars
+            .borrow_mut()
+            .entry(font.idx)
+            .or_default()
+            .insert(c);
+    }
+
     /// Embeds all loaded fonts into the document generated by the given renderer and caches a
     /// reference to them.
+    ///
+    /// Fonts loaded with [`FontData::builtin`][] are not embedded at all: the renderer is instead
+    /// told to reference the corresponding standard PDF font by name, which a compliant viewer
+    /// already ships.
+    ///
+    /// [`FontData::builtin`]: struct.FontData.html#method.builtin
     pub fn load_pdf_fonts(&mut self, renderer: &render::Renderer) -> Result<(), Error> {
         self.pdf_fonts.clear();
-        for font in &self.fonts {
+        for (idx, font) in self.fonts.iter().enumerate() {
             let pdf_font = match &font.raw_data {
-                RawFontData::Embedded(data) => renderer.load_font(&data)?,
+                RawFontData::Embedded(data) => {
+                    if self.subsetting_enabled {
+                        let rt_font = match &font.metrics {
+                            FontMetrics::RustType(rt_font) => rt_font,
+                            FontMetrics::Builtin(_) => unreachable!(
+                                "a builtin font never has RawFontData::Embedded raw data"
+                            ),
+                        };
+                        let used_chars = self.used_chars.borrow();
+                        let used_chars = used_chars.get(&idx).cloned().unwrap_or_default();
+                        let subset_data = subset_font(data, rt_font, &used_chars)?;
+                        renderer.load_font(&subset_data)?
+                    } else {
+                        renderer.load_font(data)?
+                    }
+                }
+                RawFontData::Builtin(builtin) => renderer.load_builtin_font(*builtin)?,
             };
             self.pdf_fonts.push(pdf_font);
         }
@@ -140,11 +341,19 @@ impl FontCache {
     /// Returns a reference to the Rusttype font for the given font, if available.
     ///
     /// This method may only be called with [`Font`][] instances that have been created by this
-    /// font cache.
+    /// font cache, and it panics if `font` is a [`BuiltinFont`][], which has no backing
+    /// [`rusttype::Font`][].
     ///
     /// [`Font`]: struct.Font.html
+    /// [`BuiltinFont`]: enum.BuiltinFont.html
+    /// [`rusttype::Font`]: https://docs.rs/rusttype
     pub fn get_rt_font(&self, font: Font) -> &rusttype::Font<'static> {
-        &self.fonts[font.idx].rt_font
+        match &self.fonts[font.idx].metrics {
+            FontMetrics::RustType(rt_font) => rt_font,
+            FontMetrics::Builtin(_) => {
+                panic!("get_rt_font was called with a builtin font, which has no rusttype::Font")
+            }
+        }
     }
 }
 
@@ -153,7 +362,7 @@ impl FontCache {
 /// [`FontCache`]: struct.FontCache.html
 #[derive(Clone, Debug)]
 pub struct FontData {
-    rt_font: rusttype::Font<'static>,
+    metrics: FontMetrics,
     raw_data: RawFontData,
 }
 
@@ -166,7 +375,7 @@ impl FontData {
     pub fn new(data: Vec<u8>) -> Result<FontData, rusttype::Error> {
         let rt_font = rusttype::Font::from_bytes(data.clone())?;
         Ok(FontData {
-            rt_font,
+            metrics: FontMetrics::RustType(rt_font),
             raw_data: RawFontData::Embedded(data),
         })
     }
@@ -195,11 +404,46 @@ impl FontData {
         })?;
         Ok(font_data)
     }
+
+    /// Loads one of the 14 standard PDF fonts without embedding a font program.
+    ///
+    /// The returned [`FontData`][] still has real glyph metrics, approximated from the published
+    /// Adobe Core 14 AFM widths for the printable WinAnsi/ASCII range (see [`BuiltinFont`][]), so
+    /// [`Font::char_width`][]/[`Font::str_width`][] keep working as usual.  But
+    /// [`FontCache::load_pdf_fonts`][] embeds nothing for it: it instead tells the renderer to
+    /// reference the PDF viewer's own copy of the standard font by name, which keeps the document
+    /// tiny at the cost of only reliably covering that WinAnsi-ish range — draw a character
+    /// outside of it and, unless a fallback family (see
+    /// [`FontCache::add_fallback_family`][]) covers it, it is measured with an average glyph
+    /// width instead of its real one and is drawn with this font anyway.
+    ///
+    /// [`FontData`]: struct.FontData.html
+    /// [`BuiltinFont`]: enum.BuiltinFont.html
+    /// [`Font::char_width`]: struct.Font.html#method.char_width
+    /// [`Font::str_width`]: struct.Font.html#method.str_width
+    /// [`FontCache::load_pdf_fonts`]: struct.FontCache.html#method.load_pdf_fonts
+    /// [`FontCache::add_fallback_family`]: struct.FontCache.html#method.add_fallback_family
+    pub fn builtin(font: BuiltinFont) -> FontData {
+        FontData {
+            metrics: FontMetrics::Builtin(font),
+            raw_data: RawFontData::Builtin(font),
+        }
+    }
+}
+
+/// The source of the glyph metrics backing a [`FontData`][].
+///
+/// [`FontData`]: struct.FontData.html
+#[derive(Clone, Debug)]
+enum FontMetrics {
+    RustType(rusttype::Font<'static>),
+    Builtin(BuiltinFont),
 }
 
 #[derive(Clone, Debug)]
 enum RawFontData {
     Embedded(Vec<u8>),
+    Builtin(BuiltinFont),
 }
 
 /// A collection of fonts with different styles.
@@ -246,16 +490,23 @@ pub struct Font {
 }
 
 impl Font {
-    fn new(idx: usize, rt_font: &rusttype::Font<'static>) -> Result<Font, Error> {
-        let scale = rt_font.units_per_em();
-        if scale == 0 {
-            return Err(Error::new(
-                "The font is not scalable",
-                ErrorKind::InvalidFont,
-            ));
-        }
-        let scale = f32::from(scale);
-        let v_metrics = rt_font.v_metrics_unscaled() * (1.0 / scale);
+    fn new(idx: usize, metrics: &FontMetrics) -> Result<Font, Error> {
+        let (scale, v_metrics) = match metrics {
+            FontMetrics::RustType(rt_font) => {
+                let units_per_em = rt_font.units_per_em();
+                if units_per_em == 0 {
+                    return Err(Error::new(
+                        "The font is not scalable",
+                        ErrorKind::InvalidFont,
+                    ));
+                }
+                let scale = f32::from(units_per_em);
+                (scale, rt_font.v_metrics_unscaled() * (1.0 / scale))
+            }
+            // Builtin fonts have no font program to read units_per_em/v_metrics from, so their
+            // metrics are already expressed as fractions of the em square (i.e. as if scale == 1).
+            FontMetrics::Builtin(builtin) => (1.0, builtin.v_metrics()),
+        };
         let glyph_height = v_metrics.ascent - v_metrics.descent;
         let line_height = glyph_height + v_metrics.line_gap;
         Ok(Font {
@@ -276,31 +527,425 @@ impl Font {
         self.glyph_height * f64::from(font_size)
     }
 
-    /// Returns the width of a character with this font and the given font size.
+    /// Returns the font that actually supplies `c` and the width of `c` when drawn with it.
+    ///
+    /// If this font's underlying face has no glyph for `c`, the fallback families registered with
+    /// [`FontCache::add_fallback_family`][] are searched in order (matched against `style`) and
+    /// the first one that does cover `c` is used instead; if none does, this font is used anyway
+    /// (and will render `.notdef`).
     ///
     /// The given [`FontCache`][] must be the font cache that loaded this font.
     ///
     /// [`FontCache`]: struct.FontCache.html
-    pub fn char_width(&self, font_cache: &FontCache, c: char, font_size: u8) -> Mm {
-        let glyph = font_cache
-            .get_rt_font(*self)
-            .glyph(c)
-            .standalone()
-            .get_data()
-            .expect("No data for standalone glyph");
-        let width = glyph.unit_h_metrics.advance_width / self.scale * f32::from(font_size);
-        Mm::from(printpdf::Pt(f64::from(width)))
+    /// [`FontCache::add_fallback_family`]: struct.FontCache.html#method.add_fallback_family
+    pub fn char_width(
+        &self,
+        font_cache: &FontCache,
+        c: char,
+        font_size: u8,
+        style: Style,
+    ) -> (Font, Mm) {
+        let font = font_cache.resolve_font(*self, style, c);
+        font_cache.record_usage(font, c);
+        let width = font_cache.unscaled_advance(font, c) * f32::from(font_size);
+        (font, Mm::from(printpdf::Pt(f64::from(width))))
     }
 
     /// Returns the width of a string with this font and the given font size.
     ///
+    /// Characters that are not covered by this font are measured against whichever fallback font
+    /// (see [`FontCache::add_fallback_family`][]) actually supplies them, so the returned width
+    /// matches what [`resolve_runs`][] will draw.
+    ///
+    /// If `kerning` is `true`, the kerning adjustment between each pair of adjacent characters
+    /// that resolve to the same font is added to the sum of their individual widths.  Pass
+    /// `false` to keep measuring characters as independent, unkerned advances.
+    /// [`render::Renderer::draw_str`][] uses the kerned widths only to position each
+    /// [`FontRun`][]; the characters inside a run are written as a single string, without
+    /// per-pair kerning adjustments.
+    ///
     /// The given [`FontCache`][] must be the font cache that loaded this font.
     ///
     /// [`FontCache`]: struct.FontCache.html
-    pub fn str_width(&self, font_cache: &FontCache, s: &str, font_size: u8) -> Mm {
-        s.chars()
-            .map(|c| self.char_width(font_cache, c, font_size))
-            .sum()
+    /// [`FontCache::add_fallback_family`]: struct.FontCache.html#method.add_fallback_family
+    /// [`resolve_runs`]: #method.resolve_runs
+    /// [`render::Renderer::draw_str`]: ../render/struct.Renderer.html#method.draw_str
+    /// [`FontRun`]: struct.FontRun.html
+    pub fn str_width(
+        &self,
+        font_cache: &FontCache,
+        s: &str,
+        font_size: u8,
+        style: Style,
+        kerning: bool,
+    ) -> Mm {
+        let mut total = Mm::from(printpdf::Pt(0.0));
+        let mut prev: Option<(char, Font)> = None;
+        for c in s.chars() {
+            let (font, width) = self.char_width(font_cache, c, font_size, style);
+            if kerning {
+                if let Some((prev_c, prev_font)) = prev {
+                    if prev_font == font {
+                        total += font_cache.kerning(font, prev_c, c, font_size);
+                    }
+                }
+            }
+            total += width;
+            prev = Some((c, font));
+        }
+        total
+    }
+
+    /// Splits `s` into runs of consecutive characters that resolve to the same font, following
+    /// the fallback chain described in [`char_width`][].
+    ///
+    /// The [`render`][] module uses this to know which embedded font to select before emitting
+    /// each part of a string, so that text containing characters outside this font (CJK, emoji,
+    /// …) renders instead of producing `.notdef` boxes.  Pass the same `kerning` flag here as to
+    /// [`str_width`][] so that the emitted runs line up with the measured width.
+    ///
+    /// [`char_width`]: #method.char_width
+    /// [`render`]: ../render/
+    /// [`str_width`]: #method.str_width
+    pub fn resolve_runs(
+        &self,
+        font_cache: &FontCache,
+        s: &str,
+        font_size: u8,
+        style: Style,
+        kerning: bool,
+    ) -> Vec<FontRun> {
+        let mut runs: Vec<FontRun> = Vec::new();
+        let mut prev: Option<(char, Font)> = None;
+        for c in s.chars() {
+            let (font, mut width) = self.char_width(font_cache, c, font_size, style);
+            if kerning {
+                if let Some((prev_c, prev_font)) = prev {
+                    if prev_font == font {
+                        width += font_cache.kerning(font, prev_c, c, font_size);
+                    }
+                }
+            }
+            match runs.last_mut() {
+                Some(run) if run.font == font => {
+                    run.width += width;
+                    run.text.push(c);
+                }
+                _ => runs.push(FontRun {
+                    font,
+                    width,
+                    text: c.to_string(),
+                }),
+            }
+            prev = Some((c, font));
+        }
+        runs
+    }
+
+    /// Returns the `(width, height)` bounding box that `s` occupies when drawn with this font at
+    /// `font_size` and `style`, rotated by [`style.transform()`][].
+    ///
+    /// Unrotated text (`FontTransform::None`) measures its [`str_width`][] horizontally and its
+    /// [`get_line_height`][] vertically, like before; a 90/270 degree transform swaps the two
+    /// axes, which is what a layout needs to reserve the right amount of space for text rotated
+    /// for table column headers, spine labels or side annotations.  Attach the transform to
+    /// `style` (see [`Style::with_transform`][]) instead of passing it separately, so the same
+    /// style also rotates the text when it is drawn with [`render::Renderer::draw_str`][].
+    ///
+    /// [`str_width`]: #method.str_width
+    /// [`get_line_height`]: #method.get_line_height
+    /// [`style.transform()`]: ../style/struct.Style.html#method.transform
+    /// [`Style::with_transform`]: ../style/struct.Style.html#method.with_transform
+    /// [`render::Renderer::draw_str`]: ../render/struct.Renderer.html#method.draw_str
+    pub fn bounds(
+        &self,
+        font_cache: &FontCache,
+        s: &str,
+        font_size: u8,
+        style: Style,
+        kerning: bool,
+    ) -> (Mm, Mm) {
+        let width = self.str_width(font_cache, s, font_size, style, kerning);
+        let height = self.get_line_height(font_size);
+        style.transform().transform_bounds(width, height)
+    }
+}
+
+/// A run of consecutive characters from a string that resolve to the same font.
+///
+/// Returned by [`Font::resolve_runs`][].  [`render::Renderer::draw_str`][] draws each run's
+/// [`text`][] with its own [`font`][], which is what actually makes fallback fonts render instead
+/// of just measure: the run boundaries mark exactly where the drawn [`IndirectFontRef`][] must
+/// switch.
+///
+/// [`Font::resolve_runs`]: struct.Font.html#method.resolve_runs
+/// [`render::Renderer::draw_str`]: ../render/struct.Renderer.html#method.draw_str
+/// [`text`]: #structfield.text
+/// [`font`]: #structfield.font
+/// [`IndirectFontRef`]: https://docs.rs/printpdf/0.3.2/printpdf/types/plugins/graphics/two_dimensional/font/struct.IndirectFontRef.html
+#[derive(Clone, Debug, PartialEq)]
+pub struct FontRun {
+    /// The font to draw this run with.
+    pub font: Font,
+    /// The width of this run.
+    pub width: Mm,
+    /// The characters belonging to this run, in order.
+    pub text: String,
+}
+
+/// A rotation applied to a run of text before it is drawn.
+///
+/// Adapted from plotters' `FontTransform`: text is always laid out and measured along its
+/// unrotated baseline, and a `FontTransform` only describes how that baseline is rotated when the
+/// text is finally emitted.  Attach one to a [`Style`][] with [`Style::with_transform`][] instead
+/// of threading it through the API by hand; [`Font::bounds`][] uses [`transform_bounds`][] to swap
+/// the advance and line axes so that bounding boxes stay correct for sideways text, which is
+/// needed for table column headers, spine labels and side annotations, and
+/// [`render::Renderer::draw_str`][] reads the same style's transform to advance the text cursor
+/// along the rotated baseline (via [`transform_point`][]) and to emit the matching `printpdf` text
+/// matrix rotation, so attaching a transform to a style is enough to actually draw the rotated
+/// text, not just measure it.
+///
+/// [`Style`]: ../style/struct.Style.html
+/// [`Style::with_transform`]: ../style/struct.Style.html#method.with_transform
+/// [`Font::bounds`]: struct.Font.html#method.bounds
+/// [`transform_bounds`]: #method.transform_bounds
+/// [`transform_point`]: #method.transform_point
+/// [`render::Renderer::draw_str`]: ../render/struct.Renderer.html#method.draw_str
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum FontTransform {
+    /// No rotation; text runs left to right along the baseline.
+    #[default]
+    None,
+    /// Rotated 90 degrees counter-clockwise; text runs bottom to top.
+    Rotate90,
+    /// Rotated 180 degrees; text runs right to left, upside down.
+    Rotate180,
+    /// Rotated 270 degrees counter-clockwise (90 degrees clockwise); text runs top to bottom.
+    Rotate270,
+}
+
+impl FontTransform {
+    /// Returns `true` if this transform swaps the horizontal and vertical axes, i.e. if text
+    /// drawn with it occupies as much height as it would normally occupy width, and vice versa.
+    pub fn swaps_axes(self) -> bool {
+        matches!(self, FontTransform::Rotate90 | FontTransform::Rotate270)
+    }
+
+    /// Transforms an unrotated `(width, height)` bounding box into the bounding box the same text
+    /// occupies once this rotation is applied.
+    pub fn transform_bounds(self, width: Mm, height: Mm) -> (Mm, Mm) {
+        if self.swaps_axes() {
+            (height, width)
+        } else {
+            (width, height)
+        }
+    }
+
+    /// Transforms a `(dx, dy)` offset along the unrotated baseline (`dx` along the line of text,
+    /// `dy` perpendicular to it) into the page-space `(dx, dy)` offset that corresponds to once
+    /// this rotation is applied.
+    ///
+    /// [`render::Renderer::draw_str`][] uses this to advance the text cursor from one
+    /// [`FontRun`][] to the next along the direction text actually runs in after rotation.
+    ///
+    /// [`render::Renderer::draw_str`]: ../render/struct.Renderer.html#method.draw_str
+    /// [`FontRun`]: struct.FontRun.html
+    pub fn transform_point(self, dx: Mm, dy: Mm) -> (Mm, Mm) {
+        match self {
+            FontTransform::None => (dx, dy),
+            FontTransform::Rotate90 => (-dy, dx),
+            FontTransform::Rotate180 => (-dx, -dy),
+            FontTransform::Rotate270 => (dy, -dx),
+        }
+    }
+
+    /// Returns the counter-clockwise rotation angle, in degrees, that a `printpdf` text matrix
+    /// must apply for text to run along the direction this transform describes.
+    pub(crate) fn degrees(self) -> f64 {
+        match self {
+            FontTransform::None => 0.0,
+            FontTransform::Rotate90 => 90.0,
+            FontTransform::Rotate180 => 180.0,
+            FontTransform::Rotate270 => 270.0,
+        }
+    }
+}
+
+/// One of the 14 standard PDF fonts that every compliant PDF viewer ships, so a document can
+/// reference it by name instead of embedding a font program.
+///
+/// Load one with [`FontData::builtin`][] and add it to a [`FontCache`][] like any other font.
+/// Builtin fonts only reliably cover a WinAnsi-ish encoding: [`Font::char_width`][]/
+/// [`Font::str_width`][] use metrics approximated from the published Adobe Core 14 AFM widths for
+/// the printable ASCII range (`0x20..=0x7e`), and characters outside of that range are treated as
+/// not covered by the font (see [`FontCache::add_fallback_family`][] to cover them some other
+/// way).
+///
+/// [`FontData::builtin`]: struct.FontData.html#method.builtin
+/// [`FontCache`]: struct.FontCache.html
+/// [`Font::char_width`]: struct.Font.html#method.char_width
+/// [`Font::str_width`]: struct.Font.html#method.str_width
+/// [`FontCache::add_fallback_family`]: struct.FontCache.html#method.add_fallback_family
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum BuiltinFont {
+    /// Helvetica (sans-serif regular).
+    Helvetica,
+    /// Helvetica-Bold.
+    HelveticaBold,
+    /// Helvetica-Oblique.
+    HelveticaOblique,
+    /// Helvetica-BoldOblique.
+    HelveticaBoldOblique,
+    /// Times-Roman (serif regular).
+    TimesRoman,
+    /// Times-Bold.
+    TimesBold,
+    /// Times-Italic.
+    TimesItalic,
+    /// Times-BoldItalic.
+    TimesBoldItalic,
+    /// Courier (monospace regular).
+    Courier,
+    /// Courier-Bold.
+    CourierBold,
+    /// Courier-Oblique.
+    CourierOblique,
+    /// Courier-BoldOblique.
+    CourierBoldOblique,
+    /// Symbol.
+    Symbol,
+    /// ZapfDingbats.
+    ZapfDingbats,
+}
+
+impl BuiltinFont {
+    /// Returns the standard PDF base font name, as used in a PDF `/BaseFont` entry.
+    pub fn pdf_name(self) -> &'static str {
+        match self {
+            BuiltinFont::Helvetica => "Helvetica",
+            BuiltinFont::HelveticaBold => "Helvetica-Bold",
+            BuiltinFont::HelveticaOblique => "Helvetica-Oblique",
+            BuiltinFont::HelveticaBoldOblique => "Helvetica-BoldOblique",
+            BuiltinFont::TimesRoman => "Times-Roman",
+            BuiltinFont::TimesBold => "Times-Bold",
+            BuiltinFont::TimesItalic => "Times-Italic",
+            BuiltinFont::TimesBoldItalic => "Times-BoldItalic",
+            BuiltinFont::Courier => "Courier",
+            BuiltinFont::CourierBold => "Courier-Bold",
+            BuiltinFont::CourierOblique => "Courier-Oblique",
+            BuiltinFont::CourierBoldOblique => "Courier-BoldOblique",
+            BuiltinFont::Symbol => "Symbol",
+            BuiltinFont::ZapfDingbats => "ZapfDingbats",
+        }
+    }
+
+    /// Returns `true` if this font's bundled metrics cover `c`, i.e. if `c` is in the printable
+    /// WinAnsi/ASCII range the bundled widths were taken from.
+    fn covers(self, c: char) -> bool {
+        (0x20..=0x7e).contains(&(c as u32))
+    }
+
+    /// Returns the approximate vertical metrics, as fractions of the em square, shared by the
+    /// standard 14 fonts.
+    fn v_metrics(self) -> rusttype::VMetrics {
+        rusttype::VMetrics {
+            ascent: 0.718,
+            descent: -0.207,
+            line_gap: 0.0,
+        }
+    }
+
+    /// Returns the unscaled (i.e. already divided by 1000) advance width of `c`, approximated
+    /// from the published Adobe Core 14 AFM widths for the printable WinAnsi/ASCII range, or an
+    /// average glyph width for anything outside of it.
+    fn advance_width(self, c: char) -> f32 {
+        standard14::advance_width(self, c) / 1000.0
+    }
+}
+
+/// Approximate Adobe Core 14 AFM advance widths (in 1/1000 em) for the printable ASCII range.
+///
+/// Oblique/italic faces reuse their upright counterpart's table. This is close enough for the
+/// best-effort, embedding-free, WinAnsi-only use case builtin fonts are meant for, but it is an
+/// approximation, not exact Adobe metrics.
+mod standard14 {
+    use super::BuiltinFont;
+
+    const FIRST_CHAR: u32 = 0x20;
+    const LAST_CHAR: u32 = 0x7e;
+    const CHAR_COUNT: usize = (LAST_CHAR - FIRST_CHAR + 1) as usize;
+
+    #[rustfmt::skip]
+    const HELVETICA: [u16; CHAR_COUNT] = [
+        278, 278, 355, 556, 556, 889, 667, 191, 333, 333, 389, 584, 278, 333, 278, 278,
+        556, 556, 556, 556, 556, 556, 556, 556, 556, 556, 278, 278, 584, 584, 584, 556,
+        1015, 667, 667, 722, 722, 667, 611, 778, 722, 278, 500, 667, 556, 833, 722, 778,
+        667, 778, 722, 667, 611, 722, 667, 944, 667, 667, 611, 278, 278, 278, 469, 556,
+        333, 556, 556, 500, 556, 556, 278, 556, 556, 222, 222, 500, 222, 833, 556, 556,
+        556, 556, 333, 500, 278, 556, 500, 722, 500, 500, 500, 334, 260, 334, 584,
+    ];
+
+    #[rustfmt::skip]
+    const HELVETICA_BOLD: [u16; CHAR_COUNT] = [
+        278, 333, 474, 556, 556, 889, 722, 238, 333, 333, 389, 584, 278, 333, 278, 278,
+        556, 556, 556, 556, 556, 556, 556, 556, 556, 556, 333, 333, 584, 584, 584, 611,
+        975, 722, 722, 722, 722, 667, 611, 778, 722, 278, 556, 722, 611, 833, 722, 778,
+        667, 778, 722, 667, 611, 722, 667, 944, 667, 667, 611, 333, 278, 333, 584, 556,
+        333, 556, 611, 556, 611, 556, 333, 611, 611, 278, 278, 556, 278, 889, 611, 611,
+        611, 611, 389, 556, 333, 611, 556, 778, 556, 556, 500, 389, 280, 389, 584,
+    ];
+
+    #[rustfmt::skip]
+    const TIMES_ROMAN: [u16; CHAR_COUNT] = [
+        250, 333, 408, 500, 500, 833, 778, 180, 333, 333, 500, 564, 250, 333, 250, 278,
+        500, 500, 500, 500, 500, 500, 500, 500, 500, 500, 278, 278, 564, 564, 564, 444,
+        921, 722, 667, 667, 722, 611, 556, 722, 722, 333, 389, 722, 611, 889, 722, 722,
+        556, 722, 667, 556, 611, 722, 722, 944, 722, 722, 611, 333, 278, 333, 469, 500,
+        333, 444, 500, 444, 500, 444, 333, 500, 500, 278, 278, 500, 278, 778, 500, 500,
+        500, 500, 333, 389, 278, 500, 500, 722, 500, 500, 444, 480, 200, 480, 541,
+    ];
+
+    #[rustfmt::skip]
+    const TIMES_BOLD: [u16; CHAR_COUNT] = [
+        250, 333, 555, 500, 500, 1000, 833, 278, 333, 333, 500, 570, 250, 333, 250, 278,
+        500, 500, 500, 500, 500, 500, 500, 500, 500, 500, 333, 333, 570, 570, 570, 500,
+        930, 722, 667, 722, 722, 667, 611, 778, 778, 389, 500, 778, 667, 944, 722, 778,
+        611, 778, 722, 556, 667, 722, 722, 1000, 722, 722, 667, 333, 278, 333, 581, 500,
+        333, 500, 556, 444, 556, 444, 333, 500, 556, 278, 333, 556, 278, 833, 556, 500,
+        556, 556, 444, 389, 333, 556, 500, 722, 500, 500, 444, 394, 220, 394, 520,
+    ];
+
+    const COURIER: f32 = 600.0;
+
+    /// The width used for characters outside the bundled table, and for the Symbol/ZapfDingbats
+    /// faces, whose glyph shapes are too irregular to approximate with a single narrow/wide split.
+    const AVERAGE_WIDTH: f32 = 550.0;
+
+    pub(super) fn advance_width(font: BuiltinFont, c: char) -> f32 {
+        let index = (c as u32)
+            .checked_sub(FIRST_CHAR)
+            .filter(|&i| (i as usize) < CHAR_COUNT)
+            .map(|i| i as usize);
+        match (font, index) {
+            (BuiltinFont::Courier, _)
+            | (BuiltinFont::CourierBold, _)
+            | (BuiltinFont::CourierOblique, _)
+            | (BuiltinFont::CourierBoldOblique, _) => COURIER,
+            (BuiltinFont::Symbol, _) | (BuiltinFont::ZapfDingbats, _) => AVERAGE_WIDTH,
+            (BuiltinFont::Helvetica, Some(i)) | (BuiltinFont::HelveticaOblique, Some(i)) => {
+                f32::from(HELVETICA[i])
+            }
+            (BuiltinFont::HelveticaBold, Some(i))
+            | (BuiltinFont::HelveticaBoldOblique, Some(i)) => f32::from(HELVETICA_BOLD[i]),
+            (BuiltinFont::TimesRoman, Some(i)) | (BuiltinFont::TimesItalic, Some(i)) => {
+                f32::from(TIMES_ROMAN[i])
+            }
+            (BuiltinFont::TimesBold, Some(i)) | (BuiltinFont::TimesBoldItalic, Some(i)) => {
+                f32::from(TIMES_BOLD[i])
+            }
+            (_, None) => AVERAGE_WIDTH,
+        }
     }
 }
 
@@ -314,9 +959,275 @@ impl Font {
 pub fn from_files(dir: impl AsRef<path::Path>, name: &str) -> Result<FontFamily<FontData>, Error> {
     let dir = dir.as_ref();
     Ok(FontFamily {
-        regular: FontData::load(&dir.join(format!("{}-Regular.ttf", name)))?,
-        bold: FontData::load(&dir.join(format!("{}-Bold.ttf", name)))?,
-        italic: FontData::load(&dir.join(format!("{}-Italic.ttf", name)))?,
-        bold_italic: FontData::load(&dir.join(format!("{}-BoldItalic.ttf", name)))?,
+        regular: FontData::load(dir.join(format!("{}-Regular.ttf", name)))?,
+        bold: FontData::load(dir.join(format!("{}-Bold.ttf", name)))?,
+        italic: FontData::load(dir.join(format!("{}-Italic.ttf", name)))?,
+        bold_italic: FontData::load(dir.join(format!("{}-BoldItalic.ttf", name)))?,
+    })
+}
+
+/// Loads a font family by name from the fonts installed on the system.
+///
+/// This queries the OS font database (via [`font-kit`][]) for the regular, bold, italic and bold
+/// italic faces of the family called `name`, reads their backing bytes and loads them with
+/// [`FontData::new`][].
+///
+/// Returns an error with [`ErrorKind::InvalidFont`][] if the family itself, or any of its four
+/// required style faces, cannot be found on the system.  If you would rather synthesize missing
+/// styles yourself (for example by emboldening the regular face) instead of treating a missing
+/// face as fatal, use [`from_system_best_match`][] and inspect which faces were actually found.
+///
+/// [`font-kit`]: https://docs.rs/font-kit
+/// [`FontData::new`]: struct.FontData.html#method.new
+/// [`ErrorKind::InvalidFont`]: ../error/enum.ErrorKind.html#variant.InvalidFont
+/// [`from_system_best_match`]: fn.from_system_best_match.html
+pub fn from_system(name: &str) -> Result<FontFamily<FontData>, Error> {
+    let found = from_system_best_match(name)?;
+    let require = |face: Option<FontData>, style: &str| -> Result<FontData, Error> {
+        face.ok_or_else(|| {
+            Error::new(
+                format!("No {} face found for system font family {}", style, name),
+                ErrorKind::InvalidFont,
+            )
+        })
+    };
+    Ok(FontFamily {
+        regular: require(found.regular, "regular")?,
+        bold: require(found.bold, "bold")?,
+        italic: require(found.italic, "italic")?,
+        bold_italic: require(found.bold_italic, "bold italic")?,
     })
 }
+
+/// Loads whichever regular, bold, italic and bold italic faces of the system font family `name`
+/// can actually be found, without requiring all four to be present.
+///
+/// Missing faces are returned as `None` instead of causing an error, which is useful if a caller
+/// wants to detect and synthesize the styles an installed family doesn't provide on its own.  Use
+/// [`from_system`][] instead if a missing face should just be an error.
+///
+/// [`from_system`]: fn.from_system.html
+pub fn from_system_best_match(name: &str) -> Result<FontFamily<Option<FontData>>, Error> {
+    use font_kit::properties::{Style as FontKitStyle, Weight};
+
+    Ok(FontFamily {
+        regular: load_system_face(name, Weight::NORMAL, FontKitStyle::Normal)?,
+        bold: load_system_face(name, Weight::BOLD, FontKitStyle::Normal)?,
+        italic: load_system_face(name, Weight::NORMAL, FontKitStyle::Italic)?,
+        bold_italic: load_system_face(name, Weight::BOLD, FontKitStyle::Italic)?,
+    })
+}
+
+/// Queries the system font database for the face of family `name` matching `weight` and `style`
+/// and loads it into a [`FontData`][], or returns `None` if no such face is installed.
+///
+/// The closest face of the family is only accepted if it is bold (weight 600 or more) exactly
+/// when `weight` is, and slanted (italic or oblique) exactly when `style` is.
+///
+/// [`FontData`]: struct.FontData.html
+fn load_system_face(
+    name: &str,
+    weight: font_kit::properties::Weight,
+    style: font_kit::properties::Style,
+) -> Result<Option<FontData>, Error> {
+    use font_kit::family_name::FamilyName;
+    use font_kit::properties::Properties;
+    use font_kit::source::SystemSource;
+
+    let properties = Properties {
+        style,
+        weight,
+        ..Properties::new()
+    };
+    let handle = match SystemSource::new()
+        .select_best_match(&[FamilyName::Title(name.to_string())], &properties)
+    {
+        Ok(handle) => handle,
+        Err(_) => return Ok(None),
+    };
+
+    let font = handle.load().map_err(|_| {
+        Error::new(
+            format!("Failed to load system font face for {}", name),
+            ErrorKind::InvalidFont,
+        )
+    })?;
+    // select_best_match falls back to the closest face of the family, e.g. the regular face when
+    // no bold face is installed, so check that the face it found has the requested style.
+    let found = font.properties();
+    let is_bold = |weight: font_kit::properties::Weight| weight.0 >= 600.0;
+    let is_slanted =
+        |style: font_kit::properties::Style| style != font_kit::properties::Style::Normal;
+    if is_bold(found.weight) != is_bold(weight) || is_slanted(found.style) != is_slanted(style) {
+        return Ok(None);
+    }
+    let data = font.copy_font_data().ok_or_else(|| {
+        Error::new(
+            format!(
+                "System font face for {} does not expose its backing font data",
+                name
+            ),
+            ErrorKind::InvalidFont,
+        )
+    })?;
+    let font_data = FontData::new((*data).clone()).map_err(|err| {
+        Error::new(
+            format!("Failed to load rusttype font for system font {}", name),
+            err,
+        )
+    })?;
+    Ok(Some(font_data))
+}
+
+/// Produces a font program containing only the glyphs for `used_chars` (plus whatever tables a
+/// PDF viewer requires), for embedding instead of the full `data`.
+///
+/// This maps each used character to a glyph id with `rt_font` and hands the glyph set to
+/// [`allsorts`][]' subsetter, mirroring the restrict-to-code-points step tools like `pyftsubset`
+/// perform before embedding a face.
+///
+/// [`allsorts`]: https://docs.rs/allsorts
+fn subset_font(
+    data: &[u8],
+    rt_font: &rusttype::Font<'static>,
+    used_chars: &HashSet<char>,
+) -> Result<Vec<u8>, Error> {
+    // Glyph id 0 is the .notdef glyph; allsorts::subset::subset requires it to always be kept,
+    // even though no character in `used_chars` ever maps to it.
+    let glyph_ids = sorted_unique_glyph_ids(
+        std::iter::once(0).chain(used_chars.iter().map(|&c| rt_font.glyph(c).id().0 as u16)),
+    );
+
+    let scope = allsorts::binary::read::ReadScope::new(data);
+    let font_file = scope
+        .read::<allsorts::font_data::FontData>()
+        .map_err(|err| Error::new("Failed to parse embedded font for subsetting", err))?;
+    let provider = font_file
+        .table_provider(0)
+        .map_err(|err| Error::new("Failed to read embedded font tables for subsetting", err))?;
+    allsorts::subset::subset(&provider, &glyph_ids)
+        .map_err(|err| Error::new("Failed to subset embedded font", err))
+}
+
+/// Collects `ids` into the sorted, deduplicated glyph id list [`allsorts::subset::subset`][]
+/// expects.
+///
+/// [`allsorts::subset::subset`]: https://docs.rs/allsorts
+fn sorted_unique_glyph_ids(ids: impl IntoIterator<Item = u16>) -> Vec<u16> {
+    let mut ids: Vec<u16> = ids.into_iter().collect();
+    ids.sort_unstable();
+    ids.dedup();
+    ids
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn helvetica_family() -> FontFamily<FontData> {
+        FontFamily {
+            regular: FontData::builtin(BuiltinFont::Helvetica),
+            bold: FontData::builtin(BuiltinFont::HelveticaBold),
+            italic: FontData::builtin(BuiltinFont::HelveticaOblique),
+            bold_italic: FontData::builtin(BuiltinFont::HelveticaBoldOblique),
+        }
+    }
+
+    #[test]
+    fn unscaled_advance_is_cached_across_calls() {
+        let cache = FontCache::new(helvetica_family()).expect("failed to build font cache");
+        let font = cache.default_font_family().regular;
+        let style = Style::default();
+        let (_, first) = font.char_width(&cache, 'A', 12, style);
+        let (_, second) = font.char_width(&cache, 'A', 12, style);
+        assert!(cache.metrics_cache.borrow().contains_key(&(font.idx, 'A')));
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn resolve_font_falls_back_to_original_font_without_coverage() {
+        let mut cache = FontCache::new(helvetica_family()).expect("failed to build font cache");
+        cache
+            .add_fallback_family(helvetica_family())
+            .expect("failed to add fallback family");
+        let font = cache.default_font_family().regular;
+        let style = Style::default();
+        // A CJK character is outside the WinAnsi/ASCII range every bundled BuiltinFont table
+        // covers, so neither the primary font nor the fallback family (also a BuiltinFont) has a
+        // real glyph for it: resolve_font should fall back to the original font rather than
+        // panicking or picking an arbitrary fallback.
+        let (resolved, _) = font.char_width(&cache, '\u{4e2d}', 12, style);
+        assert_eq!(resolved, font);
+    }
+
+    #[test]
+    fn subset_glyph_ids_are_sorted_and_deduped() {
+        let ids = sorted_unique_glyph_ids(vec![5, 1, 5, 3, 1, 2]);
+        assert_eq!(ids, vec![1, 2, 3, 5]);
+    }
+
+    #[test]
+    fn kerning_is_a_noop_for_builtin_fonts() {
+        let cache = FontCache::new(helvetica_family()).expect("failed to build font cache");
+        let font = cache.default_font_family().regular;
+        let style = Style::default();
+        // BuiltinFont has no kerning table (FontCache::kerning always returns zero for it), so
+        // toggling kerning on or off must not change the measured width.
+        let kerned = font.str_width(&cache, "AVAST", 12, style, true);
+        let unkerned = font.str_width(&cache, "AVAST", 12, style, false);
+        assert_eq!(kerned, unkerned);
+    }
+
+    #[test]
+    fn times_roman_i_is_wider_than_helvetica_i() {
+        // Regression test: Times-Roman's 'i' (278/1000 em) is meaningfully wider than
+        // Helvetica's (222/1000 em) because of its serifs, the opposite of what a flat
+        // narrowing of the Helvetica table would produce.
+        let helvetica_i = standard14::advance_width(BuiltinFont::Helvetica, 'i');
+        let times_i = standard14::advance_width(BuiltinFont::TimesRoman, 'i');
+        assert_eq!(helvetica_i, 222.0);
+        assert_eq!(times_i, 278.0);
+        assert!(times_i > helvetica_i);
+    }
+
+    #[test]
+    fn courier_is_always_the_same_width() {
+        for c in ['i', 'W', ' ', '~'] {
+            assert_eq!(standard14::advance_width(BuiltinFont::Courier, c), 600.0);
+            assert_eq!(
+                standard14::advance_width(BuiltinFont::CourierBoldOblique, c),
+                600.0
+            );
+        }
+    }
+
+    #[test]
+    fn advance_width_outside_bundled_range_uses_average_width() {
+        assert_eq!(
+            standard14::advance_width(BuiltinFont::Helvetica, '\u{4e2d}'),
+            550.0
+        );
+    }
+
+    #[test]
+    fn transform_bounds_swaps_axes_only_for_90_and_270_degree_rotations() {
+        let width = Mm::from(printpdf::Pt(10.0));
+        let height = Mm::from(printpdf::Pt(20.0));
+        assert_eq!(
+            FontTransform::None.transform_bounds(width, height),
+            (width, height)
+        );
+        assert_eq!(
+            FontTransform::Rotate90.transform_bounds(width, height),
+            (height, width)
+        );
+        assert_eq!(
+            FontTransform::Rotate180.transform_bounds(width, height),
+            (width, height)
+        );
+        assert_eq!(
+            FontTransform::Rotate270.transform_bounds(width, height),
+            (height, width)
+        );
+    }
+}
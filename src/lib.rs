@@ -0,0 +1,86 @@
+// SPDX-FileCopyrightText: 2020 Robin Krahl <robin.krahl@ireas.org>
+// SPDX-License-Identifier: Apache-2.0 or MIT
+
+//! A user-friendly PDF generator written in pure Rust.
+//!
+//! See the [`fonts`][] module for font handling and the [`render`][] module for the low-level
+//! drawing primitives that sit underneath it.
+//!
+//! [`fonts`]: fonts/index.html
+//! [`render`]: render/index.html
+
+pub mod error;
+pub mod fonts;
+pub mod render;
+pub mod style;
+
+pub use error::Error;
+
+use std::ops;
+
+/// A length in millimeters.
+///
+/// Most of this crate's public API measures lengths in millimeters instead of the PDF points that
+/// [`printpdf`][] uses internally, since millimeters are the more natural unit for a page layout.
+/// Convert to and from [`printpdf::Pt`][] with [`From`][]/[`Into`][].
+///
+/// [`printpdf`]: https://docs.rs/printpdf
+/// [`printpdf::Pt`]: https://docs.rs/printpdf/0.5.3/printpdf/struct.Pt.html
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+pub struct Mm(pub f64);
+
+impl From<printpdf::Pt> for Mm {
+    fn from(pt: printpdf::Pt) -> Mm {
+        Mm(printpdf::Mm::from(pt).0)
+    }
+}
+
+impl From<Mm> for printpdf::Pt {
+    fn from(mm: Mm) -> printpdf::Pt {
+        printpdf::Mm(mm.0).into()
+    }
+}
+
+impl From<Mm> for printpdf::Mm {
+    fn from(mm: Mm) -> printpdf::Mm {
+        printpdf::Mm(mm.0)
+    }
+}
+
+impl ops::Add for Mm {
+    type Output = Mm;
+
+    fn add(self, other: Mm) -> Mm {
+        Mm(self.0 + other.0)
+    }
+}
+
+impl ops::AddAssign for Mm {
+    fn add_assign(&mut self, other: Mm) {
+        self.0 += other.0;
+    }
+}
+
+impl ops::Sub for Mm {
+    type Output = Mm;
+
+    fn sub(self, other: Mm) -> Mm {
+        Mm(self.0 - other.0)
+    }
+}
+
+impl ops::Neg for Mm {
+    type Output = Mm;
+
+    fn neg(self) -> Mm {
+        Mm(-self.0)
+    }
+}
+
+impl ops::Mul<f64> for Mm {
+    type Output = Mm;
+
+    fn mul(self, factor: f64) -> Mm {
+        Mm(self.0 * factor)
+    }
+}
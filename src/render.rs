@@ -0,0 +1,160 @@
+// SPDX-FileCopyrightText: 2020 Robin Krahl <robin.krahl@ireas.org>
+// SPDX-License-Identifier: Apache-2.0 or MIT
+
+//! Low-level rendering of text onto a PDF page.
+//!
+//! A [`Renderer`][] wraps the [`printpdf`][] document that is currently being written to.  The
+//! [`fonts`][] module uses it to embed font programs ([`FontCache::load_pdf_fonts`][]) and, once a
+//! font has been embedded, to actually draw text with [`Renderer::draw_str`][].
+//!
+//! If you use the high-level interface provided by [`Document`][], you never have to touch this
+//! module directly; it exists for callers who manage their own `printpdf` document and only want
+//! to reuse the font handling in [`fonts`][].
+//!
+//! [`fonts`]: ../fonts/index.html
+//! [`Document`]: ../struct.Document.html
+//! [`FontCache::load_pdf_fonts`]: ../fonts/struct.FontCache.html#method.load_pdf_fonts
+//! [`printpdf`]: https://docs.rs/printpdf
+
+use crate::error::{Error, ErrorKind};
+use crate::fonts::{BuiltinFont, Font, FontCache};
+use crate::style::Style;
+use crate::Mm;
+
+/// Wraps the `printpdf` document that fonts are embedded into and text is drawn onto.
+///
+/// Does not derive `Debug`: `printpdf::PdfDocumentReference` does not implement it.
+pub struct Renderer {
+    doc: printpdf::PdfDocumentReference,
+}
+
+impl Renderer {
+    /// Creates a new renderer for the given `printpdf` document.
+    pub fn new(doc: printpdf::PdfDocumentReference) -> Renderer {
+        Renderer { doc }
+    }
+
+    /// Embeds the given font program and returns a reference to it.
+    ///
+    /// Called by [`FontCache::load_pdf_fonts`][] for every font added with [`FontData::new`][],
+    /// [`FontData::load`][] or a system font family.
+    ///
+    /// [`FontCache::load_pdf_fonts`]: ../fonts/struct.FontCache.html#method.load_pdf_fonts
+    /// [`FontData::new`]: ../fonts/struct.FontData.html#method.new
+    /// [`FontData::load`]: ../fonts/struct.FontData.html#method.load
+    pub fn load_font(&self, data: &[u8]) -> Result<printpdf::IndirectFontRef, Error> {
+        self.doc
+            .add_external_font(data)
+            .map_err(|err| Error::new("Failed to embed font", err))
+    }
+
+    /// Registers one of the 14 standard PDF fonts by name, without embedding a font program.
+    ///
+    /// Called by [`FontCache::load_pdf_fonts`][] for every font added with
+    /// [`FontData::builtin`][].
+    ///
+    /// [`FontCache::load_pdf_fonts`]: ../fonts/struct.FontCache.html#method.load_pdf_fonts
+    /// [`FontData::builtin`]: ../fonts/struct.FontData.html#method.builtin
+    pub fn load_builtin_font(&self, font: BuiltinFont) -> Result<printpdf::IndirectFontRef, Error> {
+        self.doc
+            .add_builtin_font(Self::to_printpdf_builtin_font(font))
+            .map_err(|err| Error::new("Failed to register builtin font", err))
+    }
+
+    fn to_printpdf_builtin_font(font: BuiltinFont) -> printpdf::BuiltinFont {
+        match font {
+            BuiltinFont::Helvetica => printpdf::BuiltinFont::Helvetica,
+            BuiltinFont::HelveticaBold => printpdf::BuiltinFont::HelveticaBold,
+            BuiltinFont::HelveticaOblique => printpdf::BuiltinFont::HelveticaOblique,
+            BuiltinFont::HelveticaBoldOblique => printpdf::BuiltinFont::HelveticaBoldOblique,
+            BuiltinFont::TimesRoman => printpdf::BuiltinFont::TimesRoman,
+            BuiltinFont::TimesBold => printpdf::BuiltinFont::TimesBold,
+            BuiltinFont::TimesItalic => printpdf::BuiltinFont::TimesItalic,
+            BuiltinFont::TimesBoldItalic => printpdf::BuiltinFont::TimesBoldItalic,
+            BuiltinFont::Courier => printpdf::BuiltinFont::Courier,
+            BuiltinFont::CourierBold => printpdf::BuiltinFont::CourierBold,
+            BuiltinFont::CourierOblique => printpdf::BuiltinFont::CourierOblique,
+            BuiltinFont::CourierBoldOblique => printpdf::BuiltinFont::CourierBoldOblique,
+            BuiltinFont::Symbol => printpdf::BuiltinFont::Symbol,
+            BuiltinFont::ZapfDingbats => printpdf::BuiltinFont::ZapfDingbats,
+        }
+    }
+
+    /// Draws `s` at `(x, y)` on `layer` with `font` at `font_size` and `style`, rotated by
+    /// `style.transform()`.
+    ///
+    /// This is the drawing counterpart of [`Font::resolve_runs`][]: `s` is split into runs that
+    /// resolve to the same font, following the fallback chain registered with
+    /// [`FontCache::add_fallback_family`][], and the embedded [`IndirectFontRef`][] is switched for
+    /// each run.  Without this, a fallback family could be registered and would be measured
+    /// correctly, but every character would still be drawn with `font`, so anything outside of it
+    /// (CJK, emoji, symbols, …) would still render as `.notdef` boxes; this is what actually makes
+    /// those characters draw instead of just measure.
+    ///
+    /// `style.transform()` (see [`Style::with_transform`][]) is applied the same way
+    /// [`Font::bounds`][] measures it: the text matrix is rotated by [`FontTransform::degrees`][],
+    /// and each run after the first is advanced from the previous one along the rotated baseline
+    /// using [`FontTransform::transform_point`][], so the drawn text matches the bounding box
+    /// `bounds` returned for the same style.
+    ///
+    /// `kerning` is passed on to [`Font::resolve_runs`][], so it changes where each run starts;
+    /// the characters inside a run are written as one string, without per-pair kerning
+    /// adjustments.
+    ///
+    /// `font` and `font_cache` must come from the same [`FontCache`][], and
+    /// [`FontCache::load_pdf_fonts`][] must have been called first so every font `s` resolves
+    /// to has an embedded [`IndirectFontRef`][].
+    ///
+    /// [`Font::resolve_runs`]: ../fonts/struct.Font.html#method.resolve_runs
+    /// [`Font::bounds`]: ../fonts/struct.Font.html#method.bounds
+    /// [`Style::with_transform`]: ../style/struct.Style.html#method.with_transform
+    /// [`FontTransform::degrees`]: ../fonts/enum.FontTransform.html#method.degrees
+    /// [`FontTransform::transform_point`]: ../fonts/enum.FontTransform.html#method.transform_point
+    /// [`FontCache`]: ../fonts/struct.FontCache.html
+    /// [`FontCache::add_fallback_family`]: ../fonts/struct.FontCache.html#method.add_fallback_family
+    /// [`FontCache::load_pdf_fonts`]: ../fonts/struct.FontCache.html#method.load_pdf_fonts
+    /// [`IndirectFontRef`]: https://docs.rs/printpdf/0.3.2/printpdf/types/plugins/graphics/two_dimensional/font/struct.IndirectFontRef.html
+    #[allow(clippy::too_many_arguments)]
+    pub fn draw_str(
+        &self,
+        layer: &printpdf::PdfLayerReference,
+        font_cache: &FontCache,
+        font: Font,
+        s: &str,
+        font_size: u8,
+        style: Style,
+        kerning: bool,
+        x: Mm,
+        y: Mm,
+    ) -> Result<(), Error> {
+        let runs = font.resolve_runs(font_cache, s, font_size, style, kerning);
+        let transform = style.transform();
+
+        layer.begin_text_section();
+        let zero = Mm::from(printpdf::Pt(0.0));
+        let mut along_baseline = zero;
+        for run in &runs {
+            let pdf_font = Self::pdf_font(font_cache, run.font)?;
+            let (dx, dy) = transform.transform_point(along_baseline, zero);
+            layer.set_text_matrix(printpdf::TextMatrix::TranslateRotate(
+                (x + dx).into(),
+                (y + dy).into(),
+                transform.degrees(),
+            ));
+            layer.set_font(pdf_font, f64::from(font_size));
+            layer.write_text(run.text.clone(), pdf_font);
+            along_baseline += run.width;
+        }
+        layer.end_text_section();
+        Ok(())
+    }
+
+    fn pdf_font(font_cache: &FontCache, font: Font) -> Result<&printpdf::IndirectFontRef, Error> {
+        font_cache.get_pdf_font(font).ok_or_else(|| {
+            Error::new(
+                "Font has not been embedded; call FontCache::load_pdf_fonts first",
+                ErrorKind::InvalidFont,
+            )
+        })
+    }
+}
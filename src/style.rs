@@ -0,0 +1,81 @@
+// SPDX-FileCopyrightText: 2020 Robin Krahl <robin.krahl@ireas.org>
+// SPDX-License-Identifier: Apache-2.0 or MIT
+
+//! Styles for text elements.
+//!
+//! A [`Style`][] controls how an element's text is drawn: whether it is bold or italic (see
+//! [`FontFamily::get`][]), and whether it is rotated with a [`FontTransform`][] before being
+//! measured ([`Font::bounds`][]) and drawn ([`render::Renderer::draw_str`][]).  Attach a
+//! transform here instead of passing one around separately when an element needs sideways text,
+//! for example a table column header, a spine label or a side annotation.
+//!
+//! [`Style`]: struct.Style.html
+//! [`FontFamily::get`]: ../fonts/struct.FontFamily.html#method.get
+//! [`FontTransform`]: ../fonts/enum.FontTransform.html
+//! [`Font::bounds`]: ../fonts/struct.Font.html#method.bounds
+//! [`render::Renderer::draw_str`]: ../render/struct.Renderer.html#method.draw_str
+
+use crate::fonts::FontTransform;
+
+/// The style of a text: bold, italic and/or rotated.
+///
+/// Styles are built with the builder methods [`bold`][], [`italic`][] and [`with_transform`][],
+/// for example `Style::new().bold().with_transform(FontTransform::Rotate90)`.
+///
+/// [`bold`]: #method.bold
+/// [`italic`]: #method.italic
+/// [`with_transform`]: #method.with_transform
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Style {
+    bold: bool,
+    italic: bool,
+    transform: FontTransform,
+}
+
+impl Style {
+    /// Creates a new style that is neither bold nor italic nor rotated.
+    pub fn new() -> Style {
+        Style::default()
+    }
+
+    /// Returns a copy of this style with bold enabled.
+    pub fn bold(mut self) -> Style {
+        self.bold = true;
+        self
+    }
+
+    /// Returns a copy of this style with italic enabled.
+    pub fn italic(mut self) -> Style {
+        self.italic = true;
+        self
+    }
+
+    /// Returns whether this style is bold.
+    pub fn is_bold(&self) -> bool {
+        self.bold
+    }
+
+    /// Returns whether this style is italic.
+    pub fn is_italic(&self) -> bool {
+        self.italic
+    }
+
+    /// Returns a copy of this style that rotates text drawn with it by `transform`.
+    ///
+    /// [`Font::bounds`][] and [`render::Renderer::draw_str`][] both read this transform back off
+    /// the style they are given, so attaching it here is enough to reserve the right layout space
+    /// for rotated text and to actually draw it rotated; neither has to be called with a separate
+    /// transform argument.
+    ///
+    /// [`Font::bounds`]: ../fonts/struct.Font.html#method.bounds
+    /// [`render::Renderer::draw_str`]: ../render/struct.Renderer.html#method.draw_str
+    pub fn with_transform(mut self, transform: FontTransform) -> Style {
+        self.transform = transform;
+        self
+    }
+
+    /// Returns the rotation applied to text drawn with this style.
+    pub fn transform(&self) -> FontTransform {
+        self.transform
+    }
+}